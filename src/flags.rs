@@ -0,0 +1,72 @@
+//! `clap` integration for deriving [`Verbosity`] from `-v`/`-q` occurrence counts
+
+use std::marker::PhantomData;
+
+use clap::Args;
+
+use crate::DefaultLevel;
+use crate::QuiteLevel;
+use crate::Verbosity;
+
+/// A `clap::Args` flag group that maps repeated `-v`/`-q` occurrences onto a [`Verbosity`]
+///
+/// `#[clap(flatten)]` this into your CLI's argument struct to get standard `-v`/`-vv`/`-q`
+/// flags wired up without hand-rolled parsing. The net offset is applied relative to
+/// `L::default_level()`, so an app can bake in a baseline other than [`Quite`] by naming a
+/// different [`DefaultLevel`] marker, _e.g._ `VerbosityFlags<TerseLevel>`.
+///
+/// ```no_run
+/// # use clap::Parser;
+/// # use verbosity::VerbosityFlags;
+/// #[derive(Parser)]
+/// struct Cli {
+///     #[clap(flatten)]
+///     verbosity: VerbosityFlags,
+/// }
+///
+/// let cli = Cli::parse();
+///
+/// cli.verbosity.install_global();
+/// ```
+///
+/// [`Verbosity`]: crate::Verbosity
+/// [`Quite`]: crate::Verbosity::Quite
+/// [`DefaultLevel`]: crate::DefaultLevel
+#[derive(Args, Clone, Copy, Debug)]
+pub struct VerbosityFlags<L: DefaultLevel = QuiteLevel> {
+    /// Increase reporting level, can be repeated, _e.g._ `-v`
+    #[clap(short, long, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// Decrease reporting level, can be repeated, _e.g._ `-q`
+    #[clap(short, long, action = clap::ArgAction::Count, conflicts_with = "verbose")]
+    quiet: u8,
+
+    #[clap(skip)]
+    default_level: PhantomData<L>,
+}
+
+impl<L: DefaultLevel> VerbosityFlags<L> {
+    /// Resolves the net `-v`/`-q` occurrence count into a [`Verbosity`]
+    ///
+    /// The net level is `verbose as i8 - quiet as i8`, offset from `L::default_level()`
+    /// and saturated onto the available [`Verbosity`] tiers
+    ///
+    /// [`Verbosity`]: crate::Verbosity
+    #[must_use]
+    pub fn verbosity(&self) -> Verbosity {
+        Verbosity::from_counts_relative_to(
+            L::default_level(),
+            i8::try_from(self.verbose).unwrap_or(i8::MAX),
+            i8::try_from(self.quiet).unwrap_or(i8::MAX),
+        )
+    }
+
+    /// Sets [`self.verbosity()`] as the global [`Verbosity`] level
+    ///
+    /// [`self.verbosity()`]: Self::verbosity
+    /// [`Verbosity`]: crate::Verbosity
+    pub fn install_global(&self) {
+        self.verbosity().set_as_global();
+    }
+}
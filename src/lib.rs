@@ -10,7 +10,8 @@
 #![doc(html_root_url = "https://docs.rs/verbosity/0.1.0")]
 
 //! Intended for use with `cli` commands this library lets you set a singleton [`Verbosity`]
-//! option to indicate different levels of reporting, i.e. `Quite` | `Terse` | `Verbose`
+//! option to indicate different levels of reporting, i.e. the three common tiers
+//! `Quite` | `Terse` | `Verbose`, plus the finer-grained `Debug` | `Trace` tiers
 //!
 //! ## Example
 //!
@@ -28,15 +29,15 @@
 //!     Quite => {}
 //!     Terse =>
 //!         println!("terse message"),
-//!     Verbose =>
+//!     Verbose | Debug | Trace =>
 //!         println!("overly verbose message for some command")
 //! }
 //! ```
 //!
-//! ## Related Crate
+//! ## `macros` Feature
 //!
-//! The [`cli-toolbox`] crate uses this library to provide a more ergonomic way of
-//! controlling reporting output
+//! Behind the `macros` feature, [`report!`] offers a more ergonomic way of controlling
+//! reporting output, built on the same singleton [`Verbosity`] level
 //!
 //! _i.e._
 //! ```no_compile
@@ -47,15 +48,25 @@
 //! level.set_as_global();
 //!
 //! report! {
-//!     @terse "terse message"
-//!     @verbose "overly verbose message for some command"
+//!     @terse "terse message";
+//!     @verbose "overly verbose message for some command";
 //! }
 //! ```
 //! [`Verbosity`]: verbosity::Verbosity
-//! [`cli-toolbox`]: <https://crates.io/crates/cli-toolbox>
+//! [`report!`]: crate::report
 
+pub use crate::verbosity::DefaultLevel;
+pub use crate::verbosity::QuiteLevel;
+pub use crate::verbosity::TerseLevel;
 pub use crate::verbosity::Verbosity;
+pub use crate::verbosity::VerboseLevel;
+#[cfg(feature = "clap")]
+pub use crate::flags::VerbosityFlags;
 
+#[cfg(feature = "clap")]
+mod flags;
+#[cfg(feature = "macros")]
+mod macros;
 #[cfg(test)]
 mod tests;
 
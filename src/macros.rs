@@ -0,0 +1,106 @@
+//! Reporting macros gated on the global [`Verbosity`] level
+//!
+//! [`Verbosity`]: crate::Verbosity
+
+/// Prints to `stdout` only when [`Verbosity::is_terse`] is `true`
+///
+/// ```rust
+/// # use verbosity::{Verbosity, terse};
+/// Verbosity::Terse.set_as_global();
+///
+/// terse!("a terse message");
+/// ```
+///
+/// [`Verbosity::is_terse`]: crate::Verbosity::is_terse
+#[macro_export]
+macro_rules! terse {
+    ($($arg:tt)*) => {
+        if $crate::Verbosity::is_terse() {
+            ::std::println!($($arg)*);
+        }
+    };
+}
+
+/// Prints to `stdout` only when [`Verbosity::is_verbose`] is `true`
+///
+/// ```rust
+/// # use verbosity::{Verbosity, verbose};
+/// Verbosity::Verbose.set_as_global();
+///
+/// verbose!("a verbose message");
+/// ```
+///
+/// [`Verbosity::is_verbose`]: crate::Verbosity::is_verbose
+#[macro_export]
+macro_rules! verbose {
+    ($($arg:tt)*) => {
+        if $crate::Verbosity::is_verbose() {
+            ::std::println!($($arg)*);
+        }
+    };
+}
+
+/// Prints to `stderr` only when [`Verbosity::is_terse`] is `true`
+///
+/// ```rust
+/// # use verbosity::{Verbosity, eterse};
+/// Verbosity::Terse.set_as_global();
+///
+/// eterse!("a terse message");
+/// ```
+///
+/// [`Verbosity::is_terse`]: crate::Verbosity::is_terse
+#[macro_export]
+macro_rules! eterse {
+    ($($arg:tt)*) => {
+        if $crate::Verbosity::is_terse() {
+            ::std::eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Prints to `stderr` only when [`Verbosity::is_verbose`] is `true`
+///
+/// ```rust
+/// # use verbosity::{Verbosity, everbose};
+/// Verbosity::Verbose.set_as_global();
+///
+/// everbose!("a verbose message");
+/// ```
+///
+/// [`Verbosity::is_verbose`]: crate::Verbosity::is_verbose
+#[macro_export]
+macro_rules! everbose {
+    ($($arg:tt)*) => {
+        if $crate::Verbosity::is_verbose() {
+            ::std::eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Combines `@terse`/`@verbose` reporting into a single statement
+///
+/// ```rust
+/// # use verbosity::{Verbosity, report};
+/// Verbosity::Verbose.set_as_global();
+///
+/// report! {
+///     @terse "terse message";
+///     @verbose "overly verbose message for some command";
+/// }
+/// ```
+#[macro_export]
+macro_rules! report {
+    ($(@terse $terse:expr;)? $(@verbose $verbose:expr;)?) => {
+        let level = $crate::Verbosity::level();
+
+        $(if level.is_at_least($crate::Verbosity::Terse) { ::std::println!("{}", $terse); })?
+        $(if level.is_at_least($crate::Verbosity::Verbose) { ::std::println!("{}", $verbose); })?
+    };
+    ($(@verbose $verbose:expr;)? $(@terse $terse:expr;)?) => {
+        let level = $crate::Verbosity::level();
+
+        $(if level.is_at_least($crate::Verbosity::Verbose) { ::std::println!("{}", $verbose); })?
+        $(if level.is_at_least($crate::Verbosity::Terse) { ::std::println!("{}", $terse); })?
+    };
+}
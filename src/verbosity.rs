@@ -1,8 +1,11 @@
 //! Global verbosity level, used for reporting
 
+use std::cell::Cell;
+use std::cell::RefCell;
 use std::fmt;
 use std::fmt::Display;
 use std::fmt::Formatter;
+use std::marker::PhantomData;
 use std::str::FromStr;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
@@ -11,7 +14,11 @@ use std::sync::Arc;
 use lazy_static::lazy_static;
 use parking_lot::RwLock;
 
-/// Verbosity level option <`Verbose`|`Terse`|`Quite`>
+/// Verbosity level option <`Trace`|`Debug`|`Verbose`|`Terse`|`Quite`>
+///
+/// `Quite`, `Terse` and `Verbose` remain the three tiers most `cli` commands reach for, with
+/// `Debug` and `Trace` available as finer-grained tiers for commands that support `-vvv`-style
+/// counting
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
 pub enum Verbosity {
     /// No output option
@@ -20,6 +27,10 @@ pub enum Verbosity {
     Terse = 1,
     /// Extended reporting option
     Verbose = 2,
+    /// Debug-level reporting option
+    Debug = 3,
+    /// Trace-level reporting option
+    Trace = 4,
 }
 
 impl Display for Verbosity {
@@ -28,6 +39,8 @@ impl Display for Verbosity {
             Self::Terse => fmt.write_str("terse"),
             Self::Verbose => fmt.write_str("verbose"),
             Self::Quite => fmt.write_str("quite"),
+            Self::Debug => fmt.write_str("debug"),
+            Self::Trace => fmt.write_str("trace"),
         }
     }
 }
@@ -40,6 +53,8 @@ impl FromStr for Verbosity {
             "terse" => Ok(Self::Terse),
             "verbose" => Ok(Self::Verbose),
             "quite" => Ok(Self::Quite),
+            "debug" => Ok(Self::Debug),
+            "trace" => Ok(Self::Trace),
             _ => Err(format!("{:?} is not a valid verbosity", source)),
         }
     }
@@ -50,6 +65,96 @@ lazy_static! {
     static ref REPORTING_SET: AtomicBool = AtomicBool::new(false);
 }
 
+thread_local! {
+    static REPORTING_OVERRIDE: RefCell<Vec<(u64, Verbosity)>> = const { RefCell::new(Vec::new()) };
+    static REPORTING_OVERRIDE_NEXT_ID: Cell<u64> = const { Cell::new(0) };
+}
+
+/// RAII guard returned by [`Verbosity::scope`] that restores the previous scoped
+/// [`Verbosity`] level when dropped
+///
+/// Each guard tracks the id of the override it pushed, so dropping removes exactly that
+/// override regardless of the order guards are dropped in — nested `scope` calls are
+/// normally dropped innermost-first via block scoping, but an out-of-order drop (_e.g._
+/// holding the outer guard longer than the inner one) still restores the correct level
+/// rather than corrupting the stack. The guard is deliberately `!Send` so it cannot be
+/// moved to, and dropped on, a different thread than the one that created it — the
+/// override stack it pops from is thread-local.
+///
+/// [`Verbosity`]: Verbosity
+/// [`Verbosity::scope`]: Verbosity::scope
+#[derive(Debug)]
+#[must_use = "the scoped verbosity override is popped when this guard is dropped"]
+pub struct VerbosityGuard {
+    id: u64,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for VerbosityGuard {
+    fn drop(&mut self) {
+        REPORTING_OVERRIDE.with(|overrides| {
+            let mut overrides = overrides.borrow_mut();
+
+            if let Some(pos) = overrides.iter().position(|&(id, _)| id == self.id) {
+                overrides.remove(pos);
+            }
+        });
+    }
+}
+
+/// Implemented by zero-sized marker types that name a compile-time default [`Verbosity`]
+///
+/// Use one of [`QuiteLevel`], [`TerseLevel`] or [`VerboseLevel`] wherever a default tier is
+/// needed at the type level, _e.g._ [`Verbosity::init`] or `VerbosityFlags<L>`.
+///
+/// [`Verbosity`]: Verbosity
+/// [`Verbosity::init`]: Verbosity::init
+pub trait DefaultLevel {
+    /// The default [`Verbosity`] level this marker type names
+    ///
+    /// [`Verbosity`]: Verbosity
+    fn default_level() -> Verbosity;
+}
+
+/// [`DefaultLevel`] marker for [`Verbosity::Quite`]
+///
+/// [`DefaultLevel`]: DefaultLevel
+/// [`Verbosity::Quite`]: Verbosity::Quite
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct QuiteLevel;
+
+impl DefaultLevel for QuiteLevel {
+    fn default_level() -> Verbosity {
+        Verbosity::Quite
+    }
+}
+
+/// [`DefaultLevel`] marker for [`Verbosity::Terse`]
+///
+/// [`DefaultLevel`]: DefaultLevel
+/// [`Verbosity::Terse`]: Verbosity::Terse
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TerseLevel;
+
+impl DefaultLevel for TerseLevel {
+    fn default_level() -> Verbosity {
+        Verbosity::Terse
+    }
+}
+
+/// [`DefaultLevel`] marker for [`Verbosity::Verbose`]
+///
+/// [`DefaultLevel`]: DefaultLevel
+/// [`Verbosity::Verbose`]: Verbosity::Verbose
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct VerboseLevel;
+
+impl DefaultLevel for VerboseLevel {
+    fn default_level() -> Verbosity {
+        Verbosity::Verbose
+    }
+}
+
 impl Verbosity {
     /// Gets the global [`Verbosity`] level
     ///
@@ -62,9 +167,44 @@ impl Verbosity {
     ///
     /// [`Verbosity`]: Verbosity
     #[must_use]
-    #[inline]
     pub fn level() -> Self {
-        *REPORTING.read()
+        REPORTING_OVERRIDE
+            .with(|overrides| overrides.borrow().last().map(|&(_, level)| level))
+            .unwrap_or_else(|| *REPORTING.read())
+    }
+
+    /// Scopes the [`Verbosity`] level to `level` for the current thread until the
+    /// returned [`VerbosityGuard`] is dropped
+    ///
+    /// Overrides nest: dropping the guard restores whatever level (global or
+    /// previously scoped) was active before the call to `scope`. This lets
+    /// subcommands or tests temporarily raise or lower reporting without
+    /// disturbing the app-wide global level.
+    ///
+    /// ```rust
+    /// # use verbosity::Verbosity;
+    /// Verbosity::Quite.set_as_global();
+    ///
+    /// {
+    ///     let _guard = Verbosity::scope(Verbosity::Verbose);
+    ///     assert_eq!(Verbosity::level(), Verbosity::Verbose);
+    /// }
+    ///
+    /// assert_eq!(Verbosity::level(), Verbosity::Quite);
+    /// ```
+    ///
+    /// [`Verbosity`]: Verbosity
+    /// [`VerbosityGuard`]: VerbosityGuard
+    pub fn scope(level: Self) -> VerbosityGuard {
+        let id = REPORTING_OVERRIDE_NEXT_ID.with(|next_id| {
+            let id = next_id.get();
+            next_id.set(id + 1);
+            id
+        });
+
+        REPORTING_OVERRIDE.with(|overrides| overrides.borrow_mut().push((id, level)));
+
+        VerbosityGuard { id, _not_send: PhantomData }
     }
 
     /// Checks if global [`Verbosity`] level is `Quite`
@@ -80,7 +220,66 @@ impl Verbosity {
     #[must_use]
     #[inline]
     pub fn is_quite() -> bool {
-        *REPORTING.read() == Self::Quite
+        Self::level() == Self::Quite
+    }
+
+    /// Checks if `self` is at least as reporting as `other`, _e.g._
+    /// `Verbosity::Debug.is_at_least(Verbosity::Terse)` is `true`
+    ///
+    /// ```rust
+    /// # use verbosity::Verbosity;
+    /// assert!(Verbosity::Debug.is_at_least(Verbosity::Terse));
+    /// assert!(!Verbosity::Terse.is_at_least(Verbosity::Debug));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn is_at_least(self, other: Self) -> bool {
+        self >= other
+    }
+
+    /// Computes a [`Verbosity`] from net `-v`/`-q` occurrence counts
+    ///
+    /// The net level is `verbose - quiet`, offset from [`Terse`] and saturated onto the
+    /// available tiers, so `-1` is [`Quite`], `0` is [`Terse`], `1` is [`Verbose`], `2` is
+    /// [`Debug`] and `3` or higher is [`Trace`]
+    ///
+    /// ```rust
+    /// # use verbosity::Verbosity;
+    /// assert_eq!(Verbosity::from_counts(0, 0), Verbosity::Terse);
+    /// assert_eq!(Verbosity::from_counts(3, 0), Verbosity::Trace);
+    /// assert_eq!(Verbosity::from_counts(0, 3), Verbosity::Quite);
+    /// ```
+    ///
+    /// [`Verbosity`]: Verbosity
+    /// [`Quite`]: Verbosity::Quite
+    /// [`Terse`]: Verbosity::Terse
+    /// [`Verbose`]: Verbosity::Verbose
+    /// [`Debug`]: Verbosity::Debug
+    /// [`Trace`]: Verbosity::Trace
+    #[must_use]
+    #[inline]
+    pub fn from_counts(verbose: i8, quiet: i8) -> Self {
+        Self::from_counts_relative_to(Self::Terse, verbose, quiet)
+    }
+
+    /// Computes a [`Verbosity`] from net `-v`/`-q` occurrence counts, offset from `base`
+    /// instead of [`Terse`]
+    ///
+    /// This is the building block behind [`from_counts`], which is equivalent to
+    /// `from_counts_relative_to(Verbosity::Terse, verbose, quiet)`
+    ///
+    /// [`Verbosity`]: Verbosity
+    /// [`Terse`]: Verbosity::Terse
+    /// [`from_counts`]: Self::from_counts
+    #[must_use]
+    pub fn from_counts_relative_to(base: Self, verbose: i8, quiet: i8) -> Self {
+        match (base as i8).saturating_add(verbose).saturating_sub(quiet) {
+            i8::MIN..=0 => Self::Quite,
+            1 => Self::Terse,
+            2 => Self::Verbose,
+            3 => Self::Debug,
+            4..=i8::MAX => Self::Trace,
+        }
     }
 
     /// Checks if global [`Verbosity`] level is `Terse`
@@ -119,7 +318,7 @@ impl Verbosity {
     #[must_use]
     #[inline]
     pub fn is_terse() -> bool {
-        *REPORTING.read() != Self::Quite
+        Self::level().is_at_least(Self::Terse)
     }
 
     /// Checks if global [`Verbosity`] level is 'Verbose'
@@ -158,7 +357,7 @@ impl Verbosity {
     #[must_use]
     #[inline]
     pub fn is_verbose() -> bool {
-        *REPORTING.read() == Self::Verbose
+        Self::level().is_at_least(Self::Verbose)
     }
 
     /// Sets the instance of a [`Verbosity`] level as the global level
@@ -189,10 +388,80 @@ impl Verbosity {
         }
     }
 
-    /// only for testing
-    #[cfg(test)]
-    #[doc(hidden)]
-    pub fn set_as_global_for_test(self) {
-        *REPORTING.write() = self;
+    /// Bakes in `L::default_level()` as the global [`Verbosity`] level
+    ///
+    /// This is equivalent to `L::default_level().set_as_global()`, letting a compile-time
+    /// [`DefaultLevel`] name the level at application start-up. Like any other
+    /// [`set_as_global`] call, it is ignored if a global level has already been set.
+    ///
+    /// ```rust
+    /// # use verbosity::{Verbosity, VerboseLevel};
+    /// Verbosity::init::<VerboseLevel>();
+    ///
+    /// assert_eq!(Verbosity::level(), Verbosity::Verbose);
+    /// ```
+    ///
+    /// [`Verbosity`]: Verbosity
+    /// [`DefaultLevel`]: DefaultLevel
+    /// [`set_as_global`]: Self::set_as_global
+    pub fn init<L: DefaultLevel>() {
+        L::default_level().set_as_global();
+    }
+
+    /// Converts a [`log::LevelFilter`] into a [`Verbosity`]
+    ///
+    /// `log::LevelFilter` has six tiers to the five of [`Verbosity`], so this mapping is lossy:
+    /// `Off`/`Error` collapse to [`Quite`], `Warn`/`Info` collapse to [`Terse`], `Debug`
+    /// maps to [`Verbose`] and `Trace` maps to [`Debug`] — [`Trace`] is never produced by
+    /// this conversion and is only reachable via [`FromStr`] or [`from_counts`]
+    ///
+    /// [`Verbosity`]: Verbosity
+    /// [`Quite`]: Verbosity::Quite
+    /// [`Terse`]: Verbosity::Terse
+    /// [`Verbose`]: Verbosity::Verbose
+    /// [`Debug`]: Verbosity::Debug
+    /// [`Trace`]: Verbosity::Trace
+    /// [`FromStr`]: std::str::FromStr
+    /// [`from_counts`]: Self::from_counts
+    #[cfg(feature = "log")]
+    #[must_use]
+    pub const fn from_level_filter(filter: log::LevelFilter) -> Self {
+        match filter {
+            log::LevelFilter::Off | log::LevelFilter::Error => Self::Quite,
+            log::LevelFilter::Warn | log::LevelFilter::Info => Self::Terse,
+            log::LevelFilter::Debug => Self::Verbose,
+            log::LevelFilter::Trace => Self::Debug,
+        }
+    }
+
+    /// Gets the [`log::LevelFilter`] for the current [`Verbosity`] level, _i.e._ the
+    /// innermost [`scope`] override on this thread if one is active, else the global level
+    ///
+    /// ```rust
+    /// # use verbosity::Verbosity;
+    /// Verbosity::Terse.set_as_global();
+    ///
+    /// assert_eq!(Verbosity::level_filter(), log::LevelFilter::Info);
+    /// ```
+    ///
+    /// [`Verbosity`]: Verbosity
+    /// [`scope`]: Self::scope
+    #[cfg(feature = "log")]
+    #[must_use]
+    #[inline]
+    pub fn level_filter() -> log::LevelFilter {
+        Self::level().into()
+    }
+}
+
+#[cfg(feature = "log")]
+impl From<Verbosity> for log::LevelFilter {
+    fn from(verbosity: Verbosity) -> Self {
+        match verbosity {
+            Verbosity::Quite => Self::Off,
+            Verbosity::Terse => Self::Info,
+            Verbosity::Verbose => Self::Debug,
+            Verbosity::Debug | Verbosity::Trace => Self::Trace,
+        }
     }
 }